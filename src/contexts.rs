@@ -0,0 +1,44 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::traits::{ConfigLoaderComponent, HasConfigPath, HasConfigType, LoadConfigFromFile};
+use crate::{CanUseComponent, DelegateComponent, HasProvider, IsProviderFor};
+
+pub struct App {
+    pub config_path: PathBuf,
+}
+
+impl HasConfigPath for App {
+    fn config_path(&self) -> &Path {
+        &self.config_path
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AppConfig {
+    pub name: String,
+}
+
+impl HasConfigType for App {
+    type Config = AppConfig;
+}
+
+pub struct AppComponents;
+
+impl HasProvider for App {
+    type Provider = AppComponents;
+}
+
+impl DelegateComponent<ConfigLoaderComponent> for AppComponents {
+    type Delegate = LoadConfigFromFile;
+}
+
+impl IsProviderFor<ConfigLoaderComponent, App> for AppComponents where
+    LoadConfigFromFile: IsProviderFor<ConfigLoaderComponent, App>
+{
+}
+
+pub trait CanUseApp: CanUseComponent<ConfigLoaderComponent> {}
+
+impl CanUseApp for App {}
@@ -0,0 +1,171 @@
+use anyhow::Error;
+use serde::de::{self, Deserializer, Unexpected};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    DelegateComponent, FormatAsJsonString, IsProviderFor, ParseFromJsonString, StringFormatter,
+    StringParser,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct TwoPointZero;
+
+impl Serialize for TwoPointZero {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("2.0")
+    }
+}
+
+impl<'de> Deserialize<'de> for TwoPointZero {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+
+        if raw == "2.0" {
+            Ok(TwoPointZero)
+        } else {
+            Err(de::Error::invalid_value(
+                Unexpected::Str(&raw),
+                &"\"2.0\"",
+            ))
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum Id {
+    Number(i64),
+    String(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct Request {
+    pub jsonrpc: TwoPointZero,
+    pub method: String,
+    pub params: Value,
+    pub id: Option<Id>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct ErrorObject {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct Response {
+    pub jsonrpc: TwoPointZero,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorObject>,
+    pub id: Option<Id>,
+}
+
+pub struct RpcRequestEncoderComponent;
+
+pub struct RpcResponseDecoderComponent;
+
+pub trait CanEncodeRpcRequest {
+    fn encode_rpc_request(&self) -> Result<String, Error>;
+}
+
+pub trait CanDecodeRpcResponse: Sized {
+    fn decode_rpc_response(raw: &str) -> Result<Self, Error>;
+}
+
+pub trait RpcRequestEncoder<Context>:
+    IsProviderFor<RpcRequestEncoderComponent, Context>
+{
+    fn encode_rpc_request(context: &Context) -> Result<String, Error>;
+}
+
+pub trait RpcResponseDecoder<Context>:
+    IsProviderFor<RpcResponseDecoderComponent, Context>
+{
+    fn decode_rpc_response(raw: &str) -> Result<Context, Error>;
+}
+
+impl<Context> CanEncodeRpcRequest for Context
+where
+    Context: crate::HasProvider,
+    Context::Provider: RpcRequestEncoder<Context>,
+{
+    fn encode_rpc_request(&self) -> Result<String, Error> {
+        Context::Provider::encode_rpc_request(self)
+    }
+}
+
+impl<Context> CanDecodeRpcResponse for Context
+where
+    Context: crate::HasProvider,
+    Context::Provider: RpcResponseDecoder<Context>,
+{
+    fn decode_rpc_response(raw: &str) -> Result<Context, Error> {
+        Context::Provider::decode_rpc_response(raw)
+    }
+}
+
+impl<Context, Component> RpcRequestEncoder<Context> for Component
+where
+    Component: DelegateComponent<RpcRequestEncoderComponent>
+        + IsProviderFor<RpcRequestEncoderComponent, Context>,
+    Component::Delegate: RpcRequestEncoder<Context>,
+{
+    fn encode_rpc_request(context: &Context) -> Result<String, Error> {
+        Component::Delegate::encode_rpc_request(context)
+    }
+}
+
+impl<Context, Component> RpcResponseDecoder<Context> for Component
+where
+    Component: DelegateComponent<RpcResponseDecoderComponent>
+        + IsProviderFor<RpcResponseDecoderComponent, Context>,
+    Component::Delegate: RpcResponseDecoder<Context>,
+{
+    fn decode_rpc_response(raw: &str) -> Result<Context, Error> {
+        Component::Delegate::decode_rpc_response(raw)
+    }
+}
+
+pub struct EncodeRpcRequestAsJson;
+
+impl<Context> RpcRequestEncoder<Context> for EncodeRpcRequestAsJson
+where
+    FormatAsJsonString: StringFormatter<Context>,
+{
+    fn encode_rpc_request(context: &Context) -> Result<String, Error> {
+        FormatAsJsonString::format_to_string(context)
+    }
+}
+
+impl<Context> IsProviderFor<RpcRequestEncoderComponent, Context> for EncodeRpcRequestAsJson where
+    FormatAsJsonString: StringFormatter<Context>
+{
+}
+
+pub struct DecodeRpcResponseFromJson;
+
+impl<Context> RpcResponseDecoder<Context> for DecodeRpcResponseFromJson
+where
+    ParseFromJsonString: StringParser<Context>,
+{
+    fn decode_rpc_response(raw: &str) -> Result<Context, Error> {
+        ParseFromJsonString::parse_from_string(raw)
+    }
+}
+
+impl<Context> IsProviderFor<RpcResponseDecoderComponent, Context> for DecodeRpcResponseFromJson where
+    ParseFromJsonString: StringParser<Context>
+{
+}
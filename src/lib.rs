@@ -1,6 +1,13 @@
+use std::marker::PhantomData;
+
 use anyhow::Error;
 use serde::{Deserialize, Serialize};
 
+pub mod contexts;
+pub mod jsonrpc;
+pub mod stream_codec;
+pub mod traits;
+
 pub trait HasProvider {
     type Provider;
 }
@@ -122,6 +129,32 @@ where
 {
 }
 
+pub trait CanBuildFromConfig: Sized {
+    type Config: for<'a> Deserialize<'a>;
+
+    fn build_from_config(config: Self::Config) -> Result<Self, Error>;
+}
+
+pub struct ParseVia<Config>(pub PhantomData<Config>);
+
+impl<Context, Config> StringParser<Context> for ParseVia<Config>
+where
+    Context: CanBuildFromConfig<Config = Config>,
+    Config: for<'a> Deserialize<'a>,
+{
+    fn parse_from_string(raw: &str) -> Result<Context, Error> {
+        let config: Config = serde_json::from_str(raw)?;
+        Context::build_from_config(config)
+    }
+}
+
+impl<Context, Config> IsProviderFor<StringParserComponent, Context> for ParseVia<Config>
+where
+    Context: CanBuildFromConfig<Config = Config>,
+    Config: for<'a> Deserialize<'a>,
+{
+}
+
 // Note: We pretend to forgot to derive Serialize here
 #[derive(Deserialize, Debug, Eq, PartialEq)]
 pub struct Person {
@@ -162,3 +195,219 @@ pub trait CanUsePerson:
 }
 
 impl CanUsePerson for Person {}
+
+pub struct BytesFormatterComponent;
+
+pub struct BytesParserComponent;
+
+pub trait CanFormatToBytes {
+    fn format_to_bytes(&self) -> Result<Vec<u8>, Error>;
+}
+
+pub trait CanParseFromBytes: Sized {
+    fn parse_from_bytes(raw: &[u8]) -> Result<Self, Error>;
+}
+
+pub trait BytesFormatter<Context>:
+    IsProviderFor<BytesFormatterComponent, Context>
+{
+    fn format_to_bytes(context: &Context) -> Result<Vec<u8>, Error>;
+}
+
+pub trait BytesParser<Context>:
+    IsProviderFor<BytesParserComponent, Context>
+{
+    fn parse_from_bytes(raw: &[u8]) -> Result<Context, Error>;
+}
+
+impl<Context> CanFormatToBytes for Context
+where
+    Context: HasProvider,
+    Context::Provider: BytesFormatter<Context>,
+{
+    fn format_to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Context::Provider::format_to_bytes(self)
+    }
+}
+
+impl<Context> CanParseFromBytes for Context
+where
+    Context: HasProvider,
+    Context::Provider: BytesParser<Context>,
+{
+    fn parse_from_bytes(raw: &[u8]) -> Result<Context, Error> {
+        Context::Provider::parse_from_bytes(raw)
+    }
+}
+
+impl<Context, Component> BytesFormatter<Context> for Component
+where
+    Component: DelegateComponent<BytesFormatterComponent>
+        + IsProviderFor<BytesFormatterComponent, Context>,
+    Component::Delegate: BytesFormatter<Context>,
+{
+    fn format_to_bytes(context: &Context) -> Result<Vec<u8>, Error> {
+        Component::Delegate::format_to_bytes(context)
+    }
+}
+
+impl<Context, Component> BytesParser<Context> for Component
+where
+    Component: DelegateComponent<BytesParserComponent>
+        + IsProviderFor<BytesParserComponent, Context>,
+    Component::Delegate: BytesParser<Context>,
+{
+    fn parse_from_bytes(raw: &[u8]) -> Result<Context, Error> {
+        Component::Delegate::parse_from_bytes(raw)
+    }
+}
+
+pub struct FormatAsYaml;
+
+impl<Context> BytesFormatter<Context> for FormatAsYaml
+where
+    Context: Serialize,
+{
+    fn format_to_bytes(context: &Context) -> Result<Vec<u8>, Error> {
+        Ok(serde_yaml::to_string(context)?.into_bytes())
+    }
+}
+
+impl<Context> IsProviderFor<BytesFormatterComponent, Context> for FormatAsYaml where
+    Context: Serialize
+{
+}
+
+pub struct ParseFromYaml;
+
+impl<Context> BytesParser<Context> for ParseFromYaml
+where
+    Context: for<'a> Deserialize<'a>,
+{
+    fn parse_from_bytes(raw: &[u8]) -> Result<Context, Error> {
+        Ok(serde_yaml::from_slice(raw)?)
+    }
+}
+
+impl<Context> IsProviderFor<BytesParserComponent, Context> for ParseFromYaml where
+    Context: for<'a> Deserialize<'a>
+{
+}
+
+pub struct FormatAsToml;
+
+impl<Context> BytesFormatter<Context> for FormatAsToml
+where
+    Context: Serialize,
+{
+    fn format_to_bytes(context: &Context) -> Result<Vec<u8>, Error> {
+        Ok(toml::to_string(context)?.into_bytes())
+    }
+}
+
+impl<Context> IsProviderFor<BytesFormatterComponent, Context> for FormatAsToml where
+    Context: Serialize
+{
+}
+
+pub struct ParseFromToml;
+
+impl<Context> BytesParser<Context> for ParseFromToml
+where
+    Context: for<'a> Deserialize<'a>,
+{
+    fn parse_from_bytes(raw: &[u8]) -> Result<Context, Error> {
+        Ok(toml::from_str(std::str::from_utf8(raw)?)?)
+    }
+}
+
+impl<Context> IsProviderFor<BytesParserComponent, Context> for ParseFromToml where
+    Context: for<'a> Deserialize<'a>
+{
+}
+
+pub struct FormatAsMessagePack;
+
+impl<Context> BytesFormatter<Context> for FormatAsMessagePack
+where
+    Context: Serialize,
+{
+    fn format_to_bytes(context: &Context) -> Result<Vec<u8>, Error> {
+        Ok(rmp_serde::to_vec(context)?)
+    }
+}
+
+impl<Context> IsProviderFor<BytesFormatterComponent, Context> for FormatAsMessagePack where
+    Context: Serialize
+{
+}
+
+pub struct ParseFromMessagePack;
+
+impl<Context> BytesParser<Context> for ParseFromMessagePack
+where
+    Context: for<'a> Deserialize<'a>,
+{
+    fn parse_from_bytes(raw: &[u8]) -> Result<Context, Error> {
+        Ok(rmp_serde::from_slice(raw)?)
+    }
+}
+
+impl<Context> IsProviderFor<BytesParserComponent, Context> for ParseFromMessagePack where
+    Context: for<'a> Deserialize<'a>
+{
+}
+
+pub trait HasIdPrefix {
+    const PREFIX: &'static str;
+}
+
+#[derive(Debug)]
+pub struct ParseIdError {
+    pub prefix: &'static str,
+}
+
+impl std::fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "identifier must start with `{}`", self.prefix)
+    }
+}
+
+impl std::error::Error for ParseIdError {}
+
+pub struct ParsePrefixedId;
+
+impl<Context> StringParser<Context> for ParsePrefixedId
+where
+    Context: HasIdPrefix + From<String>,
+{
+    fn parse_from_string(raw: &str) -> Result<Context, Error> {
+        if raw.starts_with(Context::PREFIX) {
+            Ok(Context::from(raw.to_string()))
+        } else {
+            Err(ParseIdError {
+                prefix: Context::PREFIX,
+            }
+            .into())
+        }
+    }
+}
+
+impl<Context> IsProviderFor<StringParserComponent, Context> for ParsePrefixedId where
+    Context: HasIdPrefix + From<String>
+{
+}
+
+impl<Context> StringFormatter<Context> for ParsePrefixedId
+where
+    Context: AsRef<str>,
+{
+    fn format_to_string(context: &Context) -> Result<String, Error> {
+        Ok(context.as_ref().to_string())
+    }
+}
+
+impl<Context> IsProviderFor<StringFormatterComponent, Context> for ParsePrefixedId where
+    Context: AsRef<str>
+{
+}
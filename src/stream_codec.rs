@@ -0,0 +1,121 @@
+use std::io::{BufRead, Write};
+
+use anyhow::Error;
+
+use crate::{
+    CanFormatToString, CanParseFromString, DelegateComponent, HasProvider, IsProviderFor,
+};
+
+pub struct LineReaderComponent;
+
+pub struct LineWriterComponent;
+
+pub trait CanReadFramedMessage<R>: Sized {
+    fn read_framed_message(reader: &mut R) -> Result<Option<Self>, Error>;
+}
+
+pub trait CanWriteFramedMessage<W> {
+    fn write_framed_message(&self, writer: &mut W) -> Result<(), Error>;
+}
+
+pub trait FramedMessageReader<Context, R>:
+    IsProviderFor<LineReaderComponent, Context, R>
+{
+    fn read_framed_message(reader: &mut R) -> Result<Option<Context>, Error>;
+}
+
+pub trait FramedMessageWriter<Context, W>:
+    IsProviderFor<LineWriterComponent, Context, W>
+{
+    fn write_framed_message(context: &Context, writer: &mut W) -> Result<(), Error>;
+}
+
+impl<Context, R> CanReadFramedMessage<R> for Context
+where
+    Context: HasProvider,
+    Context::Provider: FramedMessageReader<Context, R>,
+{
+    fn read_framed_message(reader: &mut R) -> Result<Option<Self>, Error> {
+        Context::Provider::read_framed_message(reader)
+    }
+}
+
+impl<Context, W> CanWriteFramedMessage<W> for Context
+where
+    Context: HasProvider,
+    Context::Provider: FramedMessageWriter<Context, W>,
+{
+    fn write_framed_message(&self, writer: &mut W) -> Result<(), Error> {
+        Context::Provider::write_framed_message(self, writer)
+    }
+}
+
+impl<Context, Component, R> FramedMessageReader<Context, R> for Component
+where
+    Component: DelegateComponent<LineReaderComponent>
+        + IsProviderFor<LineReaderComponent, Context, R>,
+    Component::Delegate: FramedMessageReader<Context, R>,
+{
+    fn read_framed_message(reader: &mut R) -> Result<Option<Context>, Error> {
+        Component::Delegate::read_framed_message(reader)
+    }
+}
+
+impl<Context, Component, W> FramedMessageWriter<Context, W> for Component
+where
+    Component: DelegateComponent<LineWriterComponent>
+        + IsProviderFor<LineWriterComponent, Context, W>,
+    Component::Delegate: FramedMessageWriter<Context, W>,
+{
+    fn write_framed_message(context: &Context, writer: &mut W) -> Result<(), Error> {
+        Component::Delegate::write_framed_message(context, writer)
+    }
+}
+
+pub struct NdjsonCodec;
+
+impl<Context, R> FramedMessageReader<Context, R> for NdjsonCodec
+where
+    Context: CanParseFromString,
+    R: BufRead,
+{
+    fn read_framed_message(reader: &mut R) -> Result<Option<Context>, Error> {
+        let mut line = String::new();
+        let bytes_read = reader.read_line(&mut line)?;
+
+        if bytes_read == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(Context::parse_from_string(
+            line.trim_end_matches(['\n', '\r']),
+        )?))
+    }
+}
+
+impl<Context, R> IsProviderFor<LineReaderComponent, Context, R> for NdjsonCodec
+where
+    Context: CanParseFromString,
+    R: BufRead,
+{
+}
+
+impl<Context, W> FramedMessageWriter<Context, W> for NdjsonCodec
+where
+    Context: CanFormatToString,
+    W: Write,
+{
+    fn write_framed_message(context: &Context, writer: &mut W) -> Result<(), Error> {
+        let line = context.format_to_string()?;
+        writeln!(writer, "{line}")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<Context, W> IsProviderFor<LineWriterComponent, Context, W> for NdjsonCodec
+where
+    Context: CanFormatToString,
+    W: Write,
+{
+}
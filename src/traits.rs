@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Error};
+use serde::Deserialize;
+
+use crate::{
+    BytesParser, DelegateComponent, HasProvider, IsProviderFor, ParseFromJsonString,
+    ParseFromToml, ParseFromYaml, StringParser,
+};
+
+pub trait HasConfigPath {
+    fn config_path(&self) -> &Path;
+}
+
+pub trait HasConfigType {
+    type Config: for<'a> Deserialize<'a>;
+}
+
+pub struct ConfigLoaderComponent;
+
+pub trait CanLoadConfig: HasConfigType {
+    fn load_config(&self) -> Result<Self::Config, Error>;
+}
+
+pub trait ConfigLoader<Context>: IsProviderFor<ConfigLoaderComponent, Context>
+where
+    Context: HasConfigType,
+{
+    fn load_config(context: &Context) -> Result<Context::Config, Error>;
+}
+
+impl<Context> CanLoadConfig for Context
+where
+    Context: HasProvider + HasConfigType,
+    Context::Provider: ConfigLoader<Context>,
+{
+    fn load_config(&self) -> Result<Self::Config, Error> {
+        Context::Provider::load_config(self)
+    }
+}
+
+impl<Context, Component> ConfigLoader<Context> for Component
+where
+    Context: HasConfigType,
+    Component: DelegateComponent<ConfigLoaderComponent>
+        + IsProviderFor<ConfigLoaderComponent, Context>,
+    Component::Delegate: ConfigLoader<Context>,
+{
+    fn load_config(context: &Context) -> Result<Context::Config, Error> {
+        Component::Delegate::load_config(context)
+    }
+}
+
+pub struct LoadConfigFromFile;
+
+impl<Context> ConfigLoader<Context> for LoadConfigFromFile
+where
+    Context: HasConfigPath + HasConfigType,
+{
+    fn load_config(context: &Context) -> Result<Context::Config, Error> {
+        let path = context.config_path();
+        let raw = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => ParseFromJsonString::parse_from_string(&raw),
+            Some("yaml") | Some("yml") => ParseFromYaml::parse_from_bytes(raw.as_bytes()),
+            Some("toml") => ParseFromToml::parse_from_bytes(raw.as_bytes()),
+            Some(ext) => Err(anyhow!("unsupported config file extension: `{ext}`")),
+            None => Err(anyhow!("config file `{}` has no extension", path.display())),
+        }
+    }
+}
+
+impl<Context> IsProviderFor<ConfigLoaderComponent, Context> for LoadConfigFromFile where
+    Context: HasConfigPath + HasConfigType
+{
+}